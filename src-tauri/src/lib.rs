@@ -1,23 +1,212 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
 use std::process::{Command, Stdio};
 #[cfg(windows)]
 use std::os::windows::process::CommandExt;
-use std::sync::Arc;
-use tauri::{Manager, WebviewWindow, AppHandle};
-use tokio::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use shared_child::SharedChild;
+use tauri::{AppHandle, Emitter, Manager, State, WebviewWindow};
+
+// A single line of backend output, tagged with the stream it came from.
+#[derive(Clone, serde::Serialize)]
+struct LogLine {
+    stream: &'static str,
+    line: String,
+}
+
+// Lifecycle requests sent to the supervisor thread, which is the sole owner of
+// `backend_process`. Each carries a reply channel so the invoking command can
+// report the result back to the frontend.
+enum BackendCommand {
+    Start(Sender<Result<u32, String>>),
+    Stop(Sender<Result<(), String>>),
+    Restart(Sender<Result<u32, String>>),
+}
 
 // State to track if backend is running
 pub struct AppState {
-    pub backend_process: Arc<Mutex<Option<std::process::Child>>>,
+    // Written by the supervisor while the app runs; the close handler takes
+    // the child out on shutdown. Manual lifecycle goes through `commands` so
+    // the supervisor applies it.
+    pub backend_process: Arc<Mutex<Option<Arc<SharedChild>>>>,
+    pub backend_config: Arc<BackendConfig>,
+    // Set when the app is tearing down so the supervisor doesn't treat a
+    // deliberate kill as a crash and restart the sidecar.
+    pub shutting_down: Arc<AtomicBool>,
+    // Channel to the supervisor for runtime start/stop/restart requests.
+    commands: Arc<Mutex<Sender<BackendCommand>>>,
 }
 
 impl Clone for AppState {
     fn clone(&self) -> Self {
         AppState {
             backend_process: Arc::clone(&self.backend_process),
+            backend_config: Arc::clone(&self.backend_config),
+            shutting_down: Arc::clone(&self.shutting_down),
+            commands: Arc::clone(&self.commands),
         }
     }
 }
 
+// Supervisor restart policy.
+const BACKEND_MAX_RETRIES: u32 = 5;
+const BACKEND_BACKOFF_INITIAL: Duration = Duration::from_millis(500);
+const BACKEND_BACKOFF_MAX: Duration = Duration::from_secs(30);
+// A run that lasts at least this long is considered healthy and resets backoff.
+const BACKEND_HEALTHY_RUN: Duration = Duration::from_secs(10);
+
+// Where the sidecar listens and how long we wait for it to come up.
+pub struct BackendConfig {
+    pub host: String,
+    pub port: u16,
+    pub health_path: String,
+    pub readiness_timeout: Duration,
+    // How long to wait for a graceful stop before hard-killing the sidecar.
+    pub shutdown_grace: Duration,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        BackendConfig {
+            host: "127.0.0.1".to_string(),
+            port: 8000,
+            health_path: "/health".to_string(),
+            readiness_timeout: Duration::from_secs(30),
+            shutdown_grace: Duration::from_secs(5),
+        }
+    }
+}
+
+impl BackendConfig {
+    // Build the probe config, letting the environment override where the
+    // sidecar actually binds. The defaults match the backend's built-in
+    // binding; `NAUTILUS_BACKEND_HOST`, `NAUTILUS_BACKEND_PORT` and
+    // `NAUTILUS_BACKEND_HEALTH_PATH` override it when a build or deployment
+    // moves the listener.
+    fn from_env() -> Self {
+        let mut config = BackendConfig::default();
+
+        if let Ok(host) = std::env::var("NAUTILUS_BACKEND_HOST") {
+            if !host.is_empty() {
+                config.host = host;
+            }
+        }
+        if let Some(port) = std::env::var("NAUTILUS_BACKEND_PORT")
+            .ok()
+            .and_then(|p| p.parse().ok())
+        {
+            config.port = port;
+        }
+        if let Ok(path) = std::env::var("NAUTILUS_BACKEND_HEALTH_PATH") {
+            if !path.is_empty() {
+                config.health_path = path;
+            }
+        }
+
+        config
+    }
+}
+
+// Ask the backend to shut down cleanly, escalating to a hard kill if it does
+// not exit within the grace period. Logs which path was taken.
+fn graceful_shutdown(child: &SharedChild, grace: Duration) {
+    let pid = child.id();
+
+    // Phase 1: request a polite stop and wait for the process to flush and exit.
+    if request_terminate(pid) {
+        let deadline = Instant::now() + grace;
+        while Instant::now() < deadline {
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    log::info!("Backend sidecar exited gracefully: {}", status);
+                    return;
+                }
+                Ok(None) => std::thread::sleep(Duration::from_millis(100)),
+                Err(_) => break,
+            }
+        }
+    }
+
+    // Phase 2: the process ignored the signal or outlived the grace period.
+    log::warn!("Backend sidecar did not stop gracefully; sending hard kill");
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(unix)]
+fn request_terminate(pid: u32) -> bool {
+    // SAFETY: kill(2) with SIGTERM targeting a child process we spawned.
+    unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) == 0 }
+}
+
+#[cfg(windows)]
+fn request_terminate(pid: u32) -> bool {
+    extern "system" {
+        fn GenerateConsoleCtrlEvent(dw_ctrl_event: u32, dw_process_group_id: u32) -> i32;
+    }
+    const CTRL_BREAK_EVENT: u32 = 1;
+    // SAFETY: FFI into kernel32 to deliver Ctrl+Break to the child's process
+    // group. The sidecar is spawned with CREATE_NEW_PROCESS_GROUP, so its PID
+    // is also its group id and the signal reaches it.
+    unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) != 0 }
+}
+
+// Issue a single HTTP GET against the health endpoint and report whether it
+// answered with a 2xx status. A bare TCP connect isn't enough: the port can be
+// open while the backend is still unable to serve requests.
+fn backend_health_ok(config: &BackendConfig) -> bool {
+    let addr = format!("{}:{}", config.host, config.port);
+    let socket_addr = match addr.to_socket_addrs().ok().and_then(|mut it| it.next()) {
+        Some(addr) => addr,
+        None => return false,
+    };
+
+    let mut stream = match TcpStream::connect_timeout(&socket_addr, Duration::from_millis(200)) {
+        Ok(stream) => stream,
+        Err(_) => return false,
+    };
+    let _ = stream.set_read_timeout(Some(Duration::from_millis(500)));
+    let _ = stream.set_write_timeout(Some(Duration::from_millis(500)));
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        config.health_path, config.host
+    );
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+
+    // The status code lives in the first line, so a small read is plenty.
+    let mut buf = [0u8; 256];
+    match stream.read(&mut buf) {
+        Ok(0) | Err(_) => false,
+        Ok(n) => {
+            let head = String::from_utf8_lossy(&buf[..n]);
+            head.split_whitespace()
+                .nth(1)
+                .is_some_and(|code| code.starts_with('2'))
+        }
+    }
+}
+
+// Poll the backend's health endpoint until it answers or the timeout elapses.
+// Returns true if the backend became ready.
+fn wait_for_backend(config: &BackendConfig) -> bool {
+    let deadline = Instant::now() + config.readiness_timeout;
+
+    while Instant::now() < deadline {
+        if backend_health_ok(config) {
+            return true;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    false
+}
+
 // Window control commands
 #[tauri::command]
 async fn win_minimize(window: WebviewWindow) -> Result<(), String> {
@@ -48,66 +237,354 @@ async fn show_window(window: WebviewWindow) -> Result<(), String> {
     window.show().map_err(|e| e.to_string())
 }
 
+// Send a lifecycle request to the supervisor and block on its reply, so manual
+// start/stop/restart is applied by the supervisor rather than racing it.
+fn dispatch_command<T>(
+    state: &AppState,
+    make: impl FnOnce(Sender<Result<T, String>>) -> BackendCommand,
+) -> Result<T, String> {
+    let (reply_tx, reply_rx) = mpsc::channel();
+    state
+        .commands
+        .lock()
+        .unwrap()
+        .send(make(reply_tx))
+        .map_err(|_| "Backend supervisor is not running".to_string())?;
+    reply_rx
+        .recv()
+        .map_err(|_| "Backend supervisor did not respond".to_string())?
+}
+
+// Spawn the sidecar if it isn't already running. Returns the new PID.
+#[tauri::command]
+fn start_backend(state: State<'_, AppState>) -> Result<u32, String> {
+    dispatch_command(&state, BackendCommand::Start)
+}
+
+// Kill the running sidecar and clear the handle.
+#[tauri::command]
+fn stop_backend(state: State<'_, AppState>) -> Result<(), String> {
+    dispatch_command(&state, BackendCommand::Stop)
+}
+
+// Stop the current sidecar (if any) and start a fresh one. Returns the new PID.
+#[tauri::command]
+fn restart_backend(state: State<'_, AppState>) -> Result<u32, String> {
+    dispatch_command(&state, BackendCommand::Restart)
+}
+
+// Build the sidecar filename for the target this binary was compiled for,
+// following Tauri's `<name>-<target-triple>` convention so ARM builds resolve
+// their own binary instead of always looking for the x86_64 one.
+fn sidecar_name() -> String {
+    let arch = std::env::consts::ARCH;
+
+    let (rest, ext) = match std::env::consts::OS {
+        "windows" => ("pc-windows-msvc", ".exe"),
+        "macos" => ("apple-darwin", ""),
+        _ => ("unknown-linux-gnu", ""),
+    };
+
+    format!("nautilus-backend-{}-{}{}", arch, rest, ext)
+}
+
 fn get_sidecar_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
-    #[cfg(target_os = "windows")]
-    let sidecar_name = "nautilus-backend-x86_64-pc-windows-msvc.exe";
-    
-    #[cfg(target_os = "linux")]
-    let sidecar_name = "nautilus-backend-x86_64-unknown-linux-gnu";
-    
-    #[cfg(target_os = "macos")]
-    let sidecar_name = "nautilus-backend-x86_64-apple-darwin";
-    
-    // Try resource dir first (production)
+    let sidecar_name = sidecar_name();
+
+    // Candidate locations: resource dir (production) then dev fallbacks.
+    let mut searched = Vec::new();
+
     if let Ok(resource_dir) = app.path().resource_dir() {
-        let sidecar_path = resource_dir.join("binaries").join(sidecar_name);
-        if sidecar_path.exists() {
-            return Ok(sidecar_path);
-        }
+        searched.push(resource_dir.join("binaries").join(&sidecar_name));
     }
-    
-    // Fallback for development
+
     if let Ok(current_dir) = std::env::current_dir() {
-        let dev_path = current_dir.join("binaries").join(sidecar_name);
-        if dev_path.exists() {
-            return Ok(dev_path);
-        }
-        
-        // Try src-tauri/binaries
-        let dev_path2 = current_dir.join("src-tauri").join("binaries").join(sidecar_name);
-        if dev_path2.exists() {
-            return Ok(dev_path2);
+        searched.push(current_dir.join("binaries").join(&sidecar_name));
+        searched.push(
+            current_dir
+                .join("src-tauri")
+                .join("binaries")
+                .join(&sidecar_name),
+        );
+    }
+
+    for candidate in &searched {
+        if candidate.exists() {
+            return Ok(candidate.clone());
         }
     }
-    
-    Err(format!("Sidecar {} not found", sidecar_name))
+
+    Err(format!(
+        "Sidecar {} not found; searched: {}",
+        sidecar_name,
+        searched
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+// Drain a child pipe line-by-line, mirroring every line to the `log` crate and
+// forwarding it to the webview. Reading to EOF also avoids the pipe filling up
+// and blocking the child.
+fn forward_output<R: std::io::Read + Send + 'static>(
+    app: AppHandle,
+    reader: R,
+    stream: &'static str,
+) {
+    std::thread::spawn(move || {
+        let buf = BufReader::new(reader);
+        for line in buf.lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => break,
+            };
+
+            if stream == "stderr" {
+                log::error!("[backend] {}", line);
+            } else {
+                log::info!("[backend] {}", line);
+            }
+
+            let _ = app.emit("backend-log", LogLine { stream, line });
+        }
+    });
 }
 
-fn start_backend_process(app: &AppHandle) -> Result<std::process::Child, String> {
+fn start_backend_process(app: &AppHandle) -> Result<Arc<SharedChild>, String> {
     let sidecar_path = get_sidecar_path(app)?;
-    
+
     log::info!("Starting backend sidecar: {:?}", sidecar_path);
-    
+
     let mut command = Command::new(&sidecar_path);
-    
+
+    // CREATE_NO_WINDOW | CREATE_NEW_PROCESS_GROUP. The new process group makes
+    // the child its own group leader, which is what lets `request_terminate`
+    // deliver a Ctrl+Break to it during graceful shutdown.
     #[cfg(windows)]
-    command.creation_flags(0x08000000); // CREATE_NO_WINDOW
-    
-    command
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("Failed to start backend: {}", e))
+    command.creation_flags(0x08000000 | 0x00000200);
+
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let child = SharedChild::spawn(&mut command)
+        .map_err(|e| format!("Failed to start backend: {}", e))?;
+
+    // Forward both streams so backend output reaches the log and the frontend.
+    if let Some(stdout) = child.take_stdout() {
+        forward_output(app.clone(), stdout, "stdout");
+    }
+    if let Some(stderr) = child.take_stderr() {
+        forward_output(app.clone(), stderr, "stderr");
+    }
+
+    Ok(Arc::new(child))
+}
+
+// Launch the sidecar, forward the handle into shared state, and (on the very
+// first launch) reveal the window, then wait for readiness.
+fn launch_backend(app_handle: &AppHandle, state: &AppState, first_start: bool) -> Result<u32, String> {
+    let child = start_backend_process(app_handle)?;
+    let pid = child.id();
+    log::info!("Backend sidecar started with PID: {}", pid);
+    *state.backend_process.lock().unwrap() = Some(child);
+
+    if first_start {
+        // Reveal the window up front so the frontend can render its spinner,
+        // then let the readiness events drive it instead of blocking the reveal
+        // on the probe (which may take up to `readiness_timeout`).
+        if let Some(window) = app_handle.get_webview_window("main") {
+            let _ = window.show();
+        }
+
+        if wait_for_backend(&state.backend_config) {
+            log::info!("Backend sidecar is ready");
+            let _ = app_handle.emit("backend-ready", ());
+        } else {
+            log::warn!(
+                "Backend sidecar did not become ready within {:?}",
+                state.backend_config.readiness_timeout
+            );
+            let _ = app_handle.emit("backend-timeout", ());
+        }
+    }
+
+    Ok(pid)
+}
+
+// Kill and reap whatever child is currently stored, clearing the handle.
+fn kill_current(state: &AppState) {
+    if let Some(child) = state.backend_process.lock().unwrap().take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+// The supervisor owns `backend_process` until shutdown, when the close handler
+// takes the child to stop it. It auto-(re)starts
+// the sidecar with exponential backoff on crash, and services manual
+// start/stop/restart requests over the command channel so the two concerns
+// never race on the same `Option`. A `paused` flag (set by `stop`) suppresses
+// auto-restart until the next explicit `start`/`restart`.
+fn supervise_backend(app_handle: AppHandle, state: AppState, commands: Receiver<BackendCommand>) {
+    let mut backoff = BACKEND_BACKOFF_INITIAL;
+    let mut retries: u32 = 0;
+    let mut first_start = true;
+    let mut paused = false;
+    let mut started: Option<Instant> = None;
+    // When to attempt the next (re)start; `None` means "as soon as possible".
+    let mut next_start_at: Option<Instant> = Some(Instant::now());
+
+    loop {
+        if state.shutting_down.load(Ordering::SeqCst) {
+            return;
+        }
+
+        // (Re)launch if we have no child, aren't paused, and the backoff has elapsed.
+        let want_launch = !paused
+            && state.backend_process.lock().unwrap().is_none()
+            && next_start_at.map_or(true, |at| Instant::now() >= at);
+
+        if want_launch {
+            match launch_backend(&app_handle, &state, first_start) {
+                Ok(_) => {
+                    first_start = false;
+                    started = Some(Instant::now());
+                    next_start_at = None;
+                }
+                Err(e) => {
+                    log::error!("Failed to start backend sidecar: {}", e);
+                    // Release any spinner waiting on the first readiness event.
+                    if first_start {
+                        first_start = false;
+                        let _ = app_handle.emit("backend-timeout", ());
+                        if let Some(window) = app_handle.get_webview_window("main") {
+                            let _ = window.show();
+                        }
+                    }
+                    if schedule_restart(&mut retries, &mut backoff, &mut next_start_at) {
+                        // Retry budget exhausted: give up auto-restarting but
+                        // stay alive in a paused state so a later Start/Restart
+                        // command can reset the counters and relaunch.
+                        let _ = app_handle.emit("backend-failed", ());
+                        paused = true;
+                    }
+                }
+            }
+            continue;
+        }
+
+        // Wait for a lifecycle command, or poll the child for an unexpected exit.
+        match commands.recv_timeout(Duration::from_millis(500)) {
+            Ok(BackendCommand::Start(reply)) => {
+                let res = if state.backend_process.lock().unwrap().is_some() {
+                    Err("Backend is already running".to_string())
+                } else {
+                    paused = false;
+                    backoff = BACKEND_BACKOFF_INITIAL;
+                    retries = 0;
+                    let res = launch_backend(&app_handle, &state, false);
+                    if res.is_ok() {
+                        started = Some(Instant::now());
+                        next_start_at = None;
+                    }
+                    res
+                };
+                let _ = reply.send(res);
+            }
+            Ok(BackendCommand::Stop(reply)) => {
+                let res = if state.backend_process.lock().unwrap().is_some() {
+                    paused = true;
+                    kill_current(&state);
+                    log::info!("Backend sidecar stopped");
+                    Ok(())
+                } else {
+                    Err("Backend is not running".to_string())
+                };
+                let _ = reply.send(res);
+            }
+            Ok(BackendCommand::Restart(reply)) => {
+                kill_current(&state);
+                paused = false;
+                backoff = BACKEND_BACKOFF_INITIAL;
+                retries = 0;
+                let res = launch_backend(&app_handle, &state, false);
+                if res.is_ok() {
+                    started = Some(Instant::now());
+                    next_start_at = None;
+                }
+                let _ = reply.send(res);
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let exited = {
+                    let backend = state.backend_process.lock().unwrap();
+                    backend.as_ref().and_then(|child| child.try_wait().ok().flatten())
+                };
+
+                if let Some(status) = exited {
+                    if state.shutting_down.load(Ordering::SeqCst) {
+                        return;
+                    }
+                    *state.backend_process.lock().unwrap() = None;
+                    log::error!("Backend sidecar exited unexpectedly: {}", status);
+                    let _ = app_handle.emit("backend-crashed", ());
+
+                    // A run that stayed up long enough is treated as healthy.
+                    if started.is_some_and(|s| s.elapsed() >= BACKEND_HEALTHY_RUN) {
+                        backoff = BACKEND_BACKOFF_INITIAL;
+                        retries = 0;
+                    }
+
+                    if schedule_restart(&mut retries, &mut backoff, &mut next_start_at) {
+                        // Give up auto-restarting but keep servicing commands so
+                        // the user's Restart button still works after a wedge.
+                        let _ = app_handle.emit("backend-failed", ());
+                        paused = true;
+                    }
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+// Record a restart attempt: bump the retry count, schedule the next launch
+// after the current backoff, and grow the backoff. Returns true if the retry
+// budget is exhausted and the supervisor should give up.
+fn schedule_restart(retries: &mut u32, backoff: &mut Duration, next: &mut Option<Instant>) -> bool {
+    *retries += 1;
+    if *retries > BACKEND_MAX_RETRIES {
+        log::error!(
+            "Backend sidecar failed after {} retries; giving up",
+            BACKEND_MAX_RETRIES
+        );
+        return true;
+    }
+
+    log::info!(
+        "Restarting backend sidecar in {:?} (attempt {}/{})",
+        backoff,
+        retries,
+        BACKEND_MAX_RETRIES
+    );
+    *next = Some(Instant::now() + *backoff);
+    *backoff = (*backoff * 2).min(BACKEND_BACKOFF_MAX);
+    false
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    let (command_tx, command_rx) = mpsc::channel::<BackendCommand>();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .manage(AppState {
             backend_process: Arc::new(Mutex::new(None)),
+            backend_config: Arc::new(BackendConfig::from_env()),
+            shutting_down: Arc::new(AtomicBool::new(false)),
+            commands: Arc::new(Mutex::new(command_tx)),
         })
-        .setup(|app| {
+        .setup(move |app| {
             // Initialize logging in debug mode
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -119,36 +596,10 @@ pub fn run() {
 
             let app_handle = app.handle().clone();
             let state = app.state::<AppState>().inner().clone();
-            
-            // Start backend sidecar in a separate thread
+
+            // Supervise the backend sidecar in a separate thread.
             std::thread::spawn(move || {
-                match start_backend_process(&app_handle) {
-                    Ok(child) => {
-                        log::info!("Backend sidecar started with PID: {}", child.id());
-                        
-                        // Store the process handle
-                        let state_clone = state.clone();
-                        let rt = tokio::runtime::Runtime::new().unwrap();
-                        rt.block_on(async {
-                            let mut backend = state_clone.backend_process.lock().await;
-                            *backend = Some(child);
-                        });
-                        
-                        // Wait a bit for backend to start, then show window
-                        std::thread::sleep(std::time::Duration::from_millis(1500));
-                        
-                        if let Some(window) = app_handle.get_webview_window("main") {
-                            let _ = window.show();
-                        }
-                    }
-                    Err(e) => {
-                        log::error!("Failed to start backend sidecar: {}", e);
-                        // Show window anyway
-                        if let Some(window) = app_handle.get_webview_window("main") {
-                            let _ = window.show();
-                        }
-                    }
-                }
+                supervise_backend(app_handle, state, command_rx);
             });
 
             Ok(())
@@ -157,14 +608,13 @@ pub fn run() {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 // Kill backend when window closes
                 let state = window.state::<AppState>();
-                let rt = tokio::runtime::Runtime::new().unwrap();
-                rt.block_on(async {
-                    let mut backend = state.backend_process.lock().await;
-                    if let Some(ref mut child) = *backend {
-                        let _ = child.kill();
-                        log::info!("Backend sidecar terminated");
-                    }
-                });
+                // Tell the supervisor this exit is intentional so it won't restart.
+                state.shutting_down.store(true, Ordering::SeqCst);
+                let child = state.backend_process.lock().unwrap().take();
+                if let Some(child) = child {
+                    graceful_shutdown(&child, state.backend_config.shutdown_grace);
+                    log::info!("Backend sidecar terminated");
+                }
             }
         })
         .invoke_handler(tauri::generate_handler![
@@ -173,6 +623,9 @@ pub fn run() {
             win_close,
             win_toggle_maximize,
             show_window,
+            start_backend,
+            stop_backend,
+            restart_backend,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");